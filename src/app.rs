@@ -0,0 +1,108 @@
+/**
+ * Copyright 2019-2022, Benjamin Vaisvil and the zenith contributors
+ */
+use crate::histogram::View;
+use crate::metrics::CPUTimeApp;
+use crate::renderer::disk::{ColorMode, DiskVisibility};
+use crate::renderer::{self, FileSystemDisplay, ZBackend};
+use crossterm::event::{KeyCode, KeyEvent};
+use tui::layout::Rect;
+use tui::style::Style;
+use tui::Frame;
+
+/// Top-level UI state for the Disk pane: what's selected and how it's displayed.
+pub struct App {
+    pub cpu_time_app: CPUTimeApp,
+    pub file_system_index: usize,
+    pub file_system_display: FileSystemDisplay,
+    pub show_inodes: bool,
+    pub disk_visibility: DiskVisibility,
+    pub color_mode: ColorMode,
+    /// `color_mode` resolved to a yes/no answer for the current tick. `ColorMode::resolve`
+    /// touches the environment and an `isatty`-style syscall, so we do that once per tick
+    /// here instead of once per cell drawn.
+    color_enabled: bool,
+}
+
+impl App {
+    pub fn new(cpu_time_app: CPUTimeApp, color_mode: ColorMode) -> App {
+        App {
+            cpu_time_app,
+            file_system_index: 0,
+            file_system_display: FileSystemDisplay::default(),
+            show_inodes: false,
+            disk_visibility: DiskVisibility::default(),
+            color_enabled: color_mode.resolve(),
+            color_mode,
+        }
+    }
+
+    pub fn on_tick(&mut self) {
+        self.cpu_time_app.update_disks();
+        self.color_enabled = self.color_mode.resolve();
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('a') => {
+                self.file_system_display = match self.file_system_display {
+                    FileSystemDisplay::Activity => FileSystemDisplay::Usage,
+                    FileSystemDisplay::Usage => FileSystemDisplay::Activity,
+                };
+            }
+            KeyCode::Char('v') => {
+                self.disk_visibility = self.disk_visibility.cycle();
+            }
+            KeyCode::Char('i') => {
+                self.show_inodes = !self.show_inodes;
+            }
+            KeyCode::Down => {
+                if let Some(next) = self.next_visible_disk_index(1) {
+                    self.file_system_index = next;
+                }
+            }
+            KeyCode::Up => {
+                if let Some(next) = self.next_visible_disk_index(-1) {
+                    self.file_system_index = next;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Walks from `file_system_index` in `step` (+1/-1) direction to the next disk
+    /// visible under `disk_visibility`, skipping hidden ones so the selection never
+    /// lands on a disk the rendered list doesn't show. Returns `None` at either end.
+    fn next_visible_disk_index(&self, step: isize) -> Option<usize> {
+        let mut i = self.file_system_index as isize;
+        loop {
+            i += step;
+            let idx = usize::try_from(i).ok()?;
+            let disk = self.cpu_time_app.disks.get(idx)?;
+            if self.disk_visibility.is_visible(&disk.disk_kind) {
+                return Some(idx);
+            }
+        }
+    }
+
+    pub fn draw_disk(
+        &self,
+        layout: Rect,
+        f: &mut Frame<'_, ZBackend>,
+        view: View,
+        border_style: Style,
+    ) {
+        renderer::disk::render_disk(
+            &self.cpu_time_app,
+            layout,
+            f,
+            view,
+            border_style,
+            &self.file_system_index,
+            &self.file_system_display,
+            &self.show_inodes,
+            &self.disk_visibility,
+            &self.color_enabled,
+        );
+    }
+}
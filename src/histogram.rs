@@ -0,0 +1,105 @@
+/**
+ * Copyright 2019-2022, Benjamin Vaisvil and the zenith contributors
+ */
+use std::collections::HashMap;
+
+/// Number of samples kept per histogram, i.e. how far back a sparkline can scroll.
+pub const HISTOGRAM_WIDTH: usize = 1024;
+
+/// Selects which window of a `Histogram`'s samples `get_zoomed` should return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    /// The full, un-zoomed sample history.
+    Default,
+    /// A zoomed-in window covering the last `n` samples.
+    Zoomed(usize),
+}
+
+impl Default for View {
+    fn default() -> Self {
+        View::Default
+    }
+}
+
+/// Identifies a single time series tracked in a `CPUTimeApp`'s `histogram_map`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HistogramKind {
+    /// Machine-wide disk read throughput, bytes/tick.
+    IoRead,
+    /// Machine-wide disk write throughput, bytes/tick.
+    IoWrite,
+    /// Per-device disk read throughput, bytes/tick, keyed by device name.
+    DiskIoRead(String),
+    /// Per-device disk write throughput, bytes/tick, keyed by device name.
+    DiskIoWrite(String),
+    /// Used space, in bytes, for the filesystem mounted at the given device name.
+    FileSystemUsedSpace(String),
+    /// Used inode count for the filesystem mounted at the given device name.
+    FileSystemUsedInodes(String),
+}
+
+/// A fixed-width ring buffer of samples rendered as a `tui::widgets::Sparkline`.
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    data: Vec<u64>,
+    width: usize,
+}
+
+impl Histogram {
+    pub fn new(width: usize) -> Histogram {
+        Histogram {
+            data: Vec::with_capacity(width),
+            width,
+        }
+    }
+
+    pub fn add(&mut self, value: u64) {
+        if self.data.len() == self.width {
+            self.data.remove(0);
+        }
+        self.data.push(value);
+    }
+
+    /// Samples oldest-first, matching the order they were recorded in.
+    pub fn data(&self) -> &[u64] {
+        &self.data
+    }
+}
+
+/// Owns every `Histogram` the app collects, keyed by `HistogramKind`.
+#[derive(Debug, Clone, Default)]
+pub struct HistogramMap {
+    map: HashMap<HistogramKind, Histogram>,
+}
+
+impl HistogramMap {
+    pub fn new() -> HistogramMap {
+        HistogramMap {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Records `value` into the histogram for `kind`, creating it on first use.
+    pub fn add_value_to(&mut self, kind: HistogramKind, value: u64) {
+        self.map
+            .entry(kind)
+            .or_insert_with(|| Histogram::new(HISTOGRAM_WIDTH))
+            .add(value);
+    }
+
+    /// Looks up the histogram for `kind`. `view` selects the zoom window; `Default`
+    /// returns the full history, `Zoomed(n)` the last `n` samples of it.
+    pub fn get_zoomed(&self, kind: &HistogramKind, view: &View) -> Option<Histogram> {
+        let h = self.map.get(kind)?;
+        match view {
+            View::Default => Some(h.clone()),
+            View::Zoomed(n) => {
+                let skip = h.data.len().saturating_sub(*n);
+                Some(Histogram {
+                    data: h.data[skip..].to_vec(),
+                    width: *n,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/**
+ * Copyright 2019-2022, Benjamin Vaisvil and the zenith contributors
+ */
+mod app;
+mod histogram;
+mod metrics;
+mod renderer;
+
+#[macro_export]
+macro_rules! float_to_byte_string {
+    ($x:expr, $unit:expr) => {{
+        let _ = $unit;
+        byte_unit::Byte::from_bytes($x as u128)
+            .get_appropriate_unit(false)
+            .to_string()
+    }};
+}
+
+use app::App;
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use renderer::ZBackend;
+use std::collections::HashMap;
+use std::io;
+use std::time::{Duration, Instant};
+use tui::backend::CrosstermBackend;
+use tui::style::Style;
+use tui::Terminal;
+
+const TICK_RATE: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), io::Error> {
+    let cpu_time_app = metrics::CPUTimeApp {
+        disks: Vec::new(),
+        histogram_map: histogram::HistogramMap::new(),
+        disk_read: 0,
+        disk_write: 0,
+        top_disk_reader_pid: None,
+        top_disk_writer_pid: None,
+        process_map: HashMap::new(),
+    };
+    let mut app = App::new(cpu_time_app, renderer::disk::ColorMode::default());
+
+    enable_raw_mode()?;
+    let backend: ZBackend = CrosstermBackend::new(io::stdout());
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut last_tick = Instant::now();
+    loop {
+        let timeout = TICK_RATE
+            .checked_sub(last_tick.elapsed())
+            .unwrap_or_else(|| Duration::from_secs(0));
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.code == KeyCode::Char('q') {
+                    break;
+                }
+                app.handle_key(key);
+            }
+        }
+        if last_tick.elapsed() >= TICK_RATE {
+            app.on_tick();
+            last_tick = Instant::now();
+        }
+
+        terminal.draw(|f| {
+            let size = f.size();
+            app.draw_disk(size, f, histogram::View::Default, Style::default());
+        })?;
+    }
+
+    disable_raw_mode()?;
+    Ok(())
+}
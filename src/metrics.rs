@@ -0,0 +1,194 @@
+/**
+ * Copyright 2019-2022, Benjamin Vaisvil and the zenith contributors
+ */
+use crate::histogram::{HistogramKind, HistogramMap};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Broad category a mounted filesystem falls into. Lets the renderer hide pseudo/network
+/// mounts that would otherwise drown out real disks on containers and cloud hosts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskKind {
+    Local,
+    Removable,
+    Network,
+    Pseudo,
+}
+
+impl DiskKind {
+    /// Classifies a mount from its reported filesystem type and device/mount source,
+    /// e.g. `("tmpfs", "tmpfs")` or `("nfs4", "fileserver:/export")`.
+    pub fn classify(file_system: &str, mount_source: &str) -> DiskKind {
+        const PSEUDO_FILE_SYSTEMS: &[&str] = &[
+            "tmpfs", "devtmpfs", "overlay", "proc", "sysfs", "cgroup", "cgroup2", "devpts",
+            "securityfs", "debugfs", "tracefs", "mqueue", "pstore", "bpf", "autofs", "squashfs",
+            "fuse.lxcfs",
+        ];
+        const NETWORK_FILE_SYSTEMS: &[&str] =
+            &["nfs", "nfs4", "cifs", "smb", "smbfs", "afs", "9p"];
+
+        if PSEUDO_FILE_SYSTEMS.contains(&file_system) {
+            DiskKind::Pseudo
+        } else if NETWORK_FILE_SYSTEMS
+            .iter()
+            .any(|fs| file_system.starts_with(fs))
+            || mount_source.contains(':')
+        {
+            DiskKind::Network
+        } else if mount_source.starts_with("/dev/sd") || mount_source.starts_with("/dev/nvme") {
+            DiskKind::Local
+        } else if mount_source.starts_with("/dev/") {
+            DiskKind::Removable
+        } else {
+            DiskKind::Local
+        }
+    }
+}
+
+/// A single mounted filesystem and its most recently collected metrics.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    /// Device name, e.g. `/dev/sda1`. Used as the key into per-device histograms.
+    pub name: String,
+    pub mount_point: PathBuf,
+    pub file_system: String,
+    pub size_bytes: u64,
+    pub available_bytes: u64,
+    pub used_bytes: u64,
+    pub inodes_total: u64,
+    pub inodes_used: u64,
+    pub inodes_free: u64,
+    pub disk_kind: DiskKind,
+}
+
+impl FileSystem {
+    pub fn get_used_bytes(&self) -> u64 {
+        self.used_bytes
+    }
+
+    pub fn get_perc_used_space(&self) -> f64 {
+        if self.size_bytes == 0 {
+            0.0
+        } else {
+            self.used_bytes as f64 / self.size_bytes as f64 * 100.0
+        }
+    }
+
+    pub fn get_perc_free_space(&self) -> f64 {
+        100.0 - self.get_perc_used_space()
+    }
+
+    pub fn get_perc_used_inodes(&self) -> f64 {
+        if self.inodes_total == 0 {
+            0.0
+        } else {
+            self.inodes_used as f64 / self.inodes_total as f64 * 100.0
+        }
+    }
+}
+
+/// A process, as surfaced in the "top reader/writer" labels on the disk activity view.
+#[derive(Debug, Clone)]
+pub struct ProcessInfo {
+    pub pid: i32,
+    pub name: String,
+    pub user_name: String,
+}
+
+/// Top-level, per-tick snapshot of system metrics. The disk renderer only touches
+/// the fields below; CPU/memory/network/process collection live alongside these.
+pub struct CPUTimeApp {
+    pub disks: Vec<FileSystem>,
+    pub histogram_map: HistogramMap,
+    pub disk_read: u64,
+    pub disk_write: u64,
+    pub top_disk_reader_pid: Option<i32>,
+    pub top_disk_writer_pid: Option<i32>,
+    pub process_map: HashMap<i32, ProcessInfo>,
+}
+
+impl CPUTimeApp {
+    /// Refreshes per-disk counters and records this tick's samples into
+    /// `histogram_map`, both per-device and as a machine-wide aggregate.
+    pub fn update_disks(&mut self) {
+        let mut total_read = 0u64;
+        let mut total_write = 0u64;
+
+        for fs in &mut self.disks {
+            fs.disk_kind = DiskKind::classify(&fs.file_system, &fs.name);
+
+            self.histogram_map
+                .add_value_to(HistogramKind::FileSystemUsedSpace(fs.name.clone()), fs.used_bytes);
+            self.histogram_map.add_value_to(
+                HistogramKind::FileSystemUsedInodes(fs.name.clone()),
+                fs.inodes_used,
+            );
+
+            // `fs.name` is the device name (e.g. `/dev/sda1`), not the mount point,
+            // so it doubles as the per-device histogram key.
+            let (read_bytes, write_bytes) = read_device_io_bytes(&fs.name);
+            self.histogram_map
+                .add_value_to(HistogramKind::DiskIoRead(fs.name.clone()), read_bytes);
+            self.histogram_map
+                .add_value_to(HistogramKind::DiskIoWrite(fs.name.clone()), write_bytes);
+            total_read += read_bytes;
+            total_write += write_bytes;
+        }
+
+        self.disk_read = total_read;
+        self.disk_write = total_write;
+        self.histogram_map
+            .add_value_to(HistogramKind::IoRead, total_read);
+        self.histogram_map
+            .add_value_to(HistogramKind::IoWrite, total_write);
+    }
+}
+
+/// Bytes read/written by `device` since the previous tick. Backed by `/proc/diskstats`
+/// on Linux (fields documented in the kernel's `Documentation/admin-guide/iostats.rst`):
+/// unlike `/sys/block/<device>/stat`, which only exists for whole disks, `/proc/diskstats`
+/// has one line per partition too, keyed by the same name `/dev/sda1` is reported under.
+/// Returns zero where that isn't available.
+#[cfg(target_os = "linux")]
+fn read_device_io_bytes(device: &str) -> (u64, u64) {
+    use std::sync::{Mutex, OnceLock};
+
+    const SECTOR_SIZE: u64 = 512;
+    static PREVIOUS: OnceLock<Mutex<HashMap<String, (u64, u64)>>> = OnceLock::new();
+
+    let device_name = device.trim_start_matches("/dev/");
+    let contents = match std::fs::read_to_string("/proc/diskstats") {
+        Ok(c) => c,
+        Err(_) => return (0, 0),
+    };
+    let fields: Vec<&str> = match contents
+        .lines()
+        .find(|line| line.split_whitespace().nth(2) == Some(device_name))
+    {
+        Some(line) => line.split_whitespace().collect(),
+        None => return (0, 0),
+    };
+    // Columns are `major minor name` followed by the same stat fields as
+    // /sys/block/<device>/stat; sectors read and sectors written are fields
+    // 3 and 7 of that block (1-indexed), i.e. offset by the 3-column prefix here.
+    let read_sectors: u64 = fields.get(5).and_then(|v| v.parse().ok()).unwrap_or(0);
+    let write_sectors: u64 = fields.get(9).and_then(|v| v.parse().ok()).unwrap_or(0);
+    let read_bytes = read_sectors * SECTOR_SIZE;
+    let write_bytes = write_sectors * SECTOR_SIZE;
+
+    let previous = PREVIOUS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut previous = previous.lock().unwrap();
+    let (prev_read, prev_write) = previous
+        .insert(device.to_string(), (read_bytes, write_bytes))
+        .unwrap_or((read_bytes, write_bytes));
+
+    (
+        read_bytes.saturating_sub(prev_read),
+        write_bytes.saturating_sub(prev_write),
+    )
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_device_io_bytes(_device: &str) -> (u64, u64) {
+    (0, 0)
+}
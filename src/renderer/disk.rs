@@ -7,12 +7,163 @@ use crate::histogram::{HistogramKind, View};
 use crate::metrics::*;
 use byte_unit::{Byte, ByteUnit};
 use std::borrow::Cow;
+use std::io::IsTerminal;
 use tui::layout::{Constraint, Direction, Layout, Rect};
 use tui::style::{Color, Modifier, Style};
 use tui::text::{Span, Spans};
 use tui::widgets::{Block, Borders, List, ListItem, Paragraph, Sparkline};
 use tui::Frame;
 
+/// How this view decides whether to emit colored styles, following hexyl's
+/// `--color always|auto|never` model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Always emit color, regardless of terminal capability.
+    Always,
+    /// Emit color unless stdout isn't an interactive terminal or `NO_COLOR` is set.
+    Auto,
+    /// Never emit color; rely on the `→` selection marker and bold instead.
+    Never,
+}
+
+impl Default for ColorMode {
+    fn default() -> Self {
+        ColorMode::Auto
+    }
+}
+
+impl ColorMode {
+    /// Resolves this mode to a yes/no answer. `Auto` touches the environment and an
+    /// `isatty`-style syscall, so callers should resolve once per tick and reuse the
+    /// result for every cell drawn that frame, rather than calling this per-cell.
+    pub fn resolve(self) -> bool {
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+/// A foreground style, or the default (no color) style when `color_enabled` is false.
+fn fg(color_enabled: bool, color: Color) -> Style {
+    if color_enabled {
+        Style::default().fg(color)
+    } else {
+        Style::default()
+    }
+}
+
+/// A bold foreground style, or bold-only when `color_enabled` is false.
+fn fg_bold(color_enabled: bool, color: Color) -> Style {
+    if color_enabled {
+        Style::default().fg(color).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().add_modifier(Modifier::BOLD)
+    }
+}
+
+/// Controls which `DiskKind`s show up in the File Systems list. Cycled with a keybind
+/// so containers/cloud hosts with dozens of overlay/tmpfs mounts can declutter the view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskVisibility {
+    /// Hide pseudo filesystems (tmpfs, overlay, proc-style mounts) only. Default.
+    HidePseudo,
+    /// Hide pseudo filesystems and network mounts, whose `statvfs` can block.
+    HidePseudoAndNetwork,
+    /// Show everything.
+    All,
+}
+
+impl Default for DiskVisibility {
+    fn default() -> Self {
+        DiskVisibility::HidePseudo
+    }
+}
+
+impl DiskVisibility {
+    pub fn cycle(self) -> Self {
+        match self {
+            DiskVisibility::HidePseudo => DiskVisibility::HidePseudoAndNetwork,
+            DiskVisibility::HidePseudoAndNetwork => DiskVisibility::All,
+            DiskVisibility::All => DiskVisibility::HidePseudo,
+        }
+    }
+
+    pub(crate) fn is_visible(self, kind: &DiskKind) -> bool {
+        match (self, kind) {
+            (DiskVisibility::All, _) => true,
+            (DiskVisibility::HidePseudo, DiskKind::Pseudo) => false,
+            (DiskVisibility::HidePseudoAndNetwork, DiskKind::Pseudo) => false,
+            (DiskVisibility::HidePseudoAndNetwork, DiskKind::Network) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Above this percentage used, either space or inodes are considered near exhaustion
+/// and the File Systems list entry is flagged red regardless of the other metric.
+const NEAR_EXHAUSTION_PCT: f64 = 90.0;
+
+/// Histogram samples are collected once per tick, same resolution as the R/s and W/s
+/// throughput figures rendered elsewhere in this view.
+const SAMPLE_INTERVAL_SECS: u64 = 1;
+/// ETAs beyond this horizon aren't actionable; collapse them to a "> 30d" label.
+const MAX_ETA_SECS: f64 = 30.0 * 24.0 * 60.0 * 60.0;
+
+/// Fit a least-squares line over `used_history` (the full, un-zoomed sample history,
+/// so the fit isn't skewed by whatever window the sparkline happens to be zoomed to)
+/// and project when `size_bytes` will be reached, returning a short human label for
+/// the title bar. Only a leading run of zero samples (ticks on which this filesystem
+/// reported no used bytes) is trimmed; interior zeros are kept so sample spacing stays uniform
+/// and `SAMPLE_INTERVAL_SECS` remains a valid time step between consecutive points.
+fn estimate_full_eta(used_history: &[u64], size_bytes: u64, current_used: u64) -> String {
+    let start = used_history
+        .iter()
+        .position(|&y| y != 0)
+        .unwrap_or(used_history.len());
+    let samples: Vec<f64> = used_history[start..].iter().map(|&y| y as f64).collect();
+    let n = samples.len() as f64;
+    if samples.len() < 2 {
+        return "unknown".to_string();
+    }
+
+    let mut sum_x = 0.0;
+    let mut sum_y = 0.0;
+    let mut sum_xy = 0.0;
+    let mut sum_x2 = 0.0;
+    for (i, y) in samples.iter().enumerate() {
+        let x = i as f64;
+        sum_x += x;
+        sum_y += y;
+        sum_xy += x * y;
+        sum_x2 += x * x;
+    }
+
+    let denominator = n * sum_x2 - sum_x * sum_x;
+    if denominator == 0.0 {
+        return "stable/shrinking".to_string();
+    }
+
+    let slope = (n * sum_xy - sum_x * sum_y) / denominator;
+    if slope <= 0.0 {
+        return "stable/shrinking".to_string();
+    }
+
+    let remaining_bytes = (size_bytes as f64 - current_used as f64).max(0.0);
+    let eta_secs = (remaining_bytes / slope) * SAMPLE_INTERVAL_SECS as f64;
+    if eta_secs > MAX_ETA_SECS {
+        return "> 30d".to_string();
+    }
+
+    let total_secs = eta_secs.round() as u64;
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    format!("~{}h{}m", hours, minutes)
+}
+
 pub fn render_disk(
     app: &CPUTimeApp,
     layout: Rect,
@@ -21,6 +172,9 @@ pub fn render_disk(
     border_style: Style,
     file_system_index: &usize,
     file_system_display: &FileSystemDisplay,
+    show_inodes: &bool,
+    disk_visibility: &DiskVisibility,
+    color_enabled: &bool,
 ) {
     let (disk_layout, view) = split_left_right_pane("Disk", layout, f, view, border_style);
     let area = Layout::default()
@@ -30,20 +184,23 @@ pub fn render_disk(
         .split(disk_layout[1]);
 
     if *file_system_display == FileSystemDisplay::Activity {
-        disk_activity_histogram(app, f, view, &area);
+        disk_activity_histogram(app, f, view, &area, file_system_index, color_enabled);
     } else {
-        disk_usage(app, f, view, &area, file_system_index);
+        disk_usage(app, f, view, &area, file_system_index, show_inodes, color_enabled);
     }
 
     let disks: Vec<_> = app
         .disks
         .iter()
         .enumerate()
+        .filter(|(_, d)| disk_visibility.is_visible(&d.disk_kind))
         .map(|(i, d)| {
-            let style = if d.get_perc_free_space() < 10.0 {
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            let style = if d.get_perc_free_space() < 10.0
+                || d.get_perc_used_inodes() > NEAR_EXHAUSTION_PCT
+            {
+                fg_bold(*color_enabled, Color::Red)
             } else {
-                Style::default().fg(Color::Green)
+                fg(*color_enabled, Color::Green)
             };
             if *file_system_index == i {
                 Span::styled(
@@ -71,7 +228,7 @@ pub fn render_disk(
         .block(
             Block::default()
                 .title(Span::styled(
-                    "File Systems [(a)ctivity/usage]",
+                    "File Systems [(a)ctivity/usage] [(i)nodes] [(v)isibility]",
                     border_style,
                 ))
                 .borders(Borders::ALL)
@@ -84,9 +241,25 @@ fn disk_activity_histogram(
     f: &mut Frame<'_, ZBackend>,
     view: View,
     area: &[Rect],
+    file_system_index: &usize,
+    color_enabled: &bool,
 ) {
+    let selected_device = app.disks.get(*file_system_index).map(|fs| fs.name.clone());
+
+    let (read_kind, write_kind) = match &selected_device {
+        Some(name) => (
+            HistogramKind::DiskIoRead(name.clone()),
+            HistogramKind::DiskIoWrite(name.clone()),
+        ),
+        None => (HistogramKind::IoRead, HistogramKind::IoWrite),
+    };
+
     let read_up = float_to_byte_string!(app.disk_read as f64, ByteUnit::B);
-    let h_read = match app.histogram_map.get_zoomed(&HistogramKind::IoRead, &view) {
+    let h_read = match app
+        .histogram_map
+        .get_zoomed(&read_kind, &view)
+        .or_else(|| app.histogram_map.get_zoomed(&HistogramKind::IoRead, &view))
+    {
         Some(h) => h,
         None => return,
     };
@@ -106,7 +279,11 @@ fn disk_activity_histogram(
     };
 
     let write_down = float_to_byte_string!(app.disk_write as f64, ByteUnit::B);
-    let h_write = match app.histogram_map.get_zoomed(&HistogramKind::IoWrite, &view) {
+    let h_write = match app
+        .histogram_map
+        .get_zoomed(&write_kind, &view)
+        .or_else(|| app.histogram_map.get_zoomed(&HistogramKind::IoWrite, &view))
+    {
         Some(h) => h,
         None => return,
     };
@@ -124,18 +301,31 @@ fn disk_activity_histogram(
         },
         None => String::from(""),
     };
+    let device_label = match &selected_device {
+        Some(name) => format!("{} ", name),
+        None => String::from(""),
+    };
+    // With a device selected, the current rate in the title is that device's latest sample.
+    let (read_up, write_down) = match &selected_device {
+        Some(_) => (
+            float_to_byte_string!(h_read.data().last().copied().unwrap_or(0) as f64, ByteUnit::B),
+            float_to_byte_string!(h_write.data().last().copied().unwrap_or(0) as f64, ByteUnit::B),
+        ),
+        None => (read_up, write_down),
+    };
+
     Sparkline::default()
         .block(
             Block::default().title(
                 format!(
-                    "R [{:^10}/s] Max [{:^10}/s] {:}",
-                    read_up, read_max_bytes, top_reader
+                    "{:}R [{:^10}/s] Max [{:^10}/s] {:}",
+                    device_label, read_up, read_max_bytes, top_reader
                 )
                 .as_str(),
             ),
         )
         .data(h_read.data())
-        .style(Style::default().fg(Color::LightYellow))
+        .style(fg(*color_enabled, Color::LightYellow))
         .max(read_max)
         .render(f, area[0]);
 
@@ -143,14 +333,14 @@ fn disk_activity_histogram(
         .block(
             Block::default().title(
                 format!(
-                    "W [{:^10}/s] Max [{:^10}/s] {:}",
-                    write_down, write_max_bytes, top_writer
+                    "{:}W [{:^10}/s] Max [{:^10}/s] {:}",
+                    device_label, write_down, write_max_bytes, top_writer
                 )
                 .as_str(),
             ),
         )
         .data(h_write.data())
-        .style(Style::default().fg(Color::LightMagenta))
+        .style(fg(*color_enabled, Color::LightMagenta))
         .max(write_max)
         .render(f, area[1]);
 }
@@ -161,43 +351,83 @@ fn disk_usage(
     view: View,
     area: &[Rect],
     file_system_index: &usize,
+    show_inodes: &bool,
+    color_enabled: &bool,
 ) {
     if let Some(fs) = app.disks.get(*file_system_index) {
-        let h_used = match app
-            .histogram_map
-            .get_zoomed(&HistogramKind::FileSystemUsedSpace(fs.name.clone()), &view)
-        {
-            Some(h) => h,
-            None => return,
-        };
         let free = float_to_byte_string!(fs.available_bytes as f64, ByteUnit::B);
         let used = float_to_byte_string!(fs.get_used_bytes() as f64, ByteUnit::B);
         let size = float_to_byte_string!(fs.size_bytes as f64, ByteUnit::B);
-        Sparkline::default()
-            .block(
-                Block::default().title(
-                    format!(
-                        "{}  ↓Used [{:^10} ({:.1}%)] Free [{:^10} ({:.1}%)] Size [{:^10}]",
-                        fs.name,
-                        used,
-                        fs.get_perc_used_space(),
-                        free,
-                        fs.get_perc_free_space(),
-                        size
-                    )
-                    .as_str(),
-                ),
-            )
-            .data(h_used.data())
-            .style(Style::default().fg(Color::LightYellow))
-            .max(fs.size_bytes)
-            .render(f, area[0]);
+
+        if *show_inodes {
+            let h_inodes = match app.histogram_map.get_zoomed(
+                &HistogramKind::FileSystemUsedInodes(fs.name.clone()),
+                &view,
+            ) {
+                Some(h) => h,
+                None => return,
+            };
+            Sparkline::default()
+                .block(
+                    Block::default().title(
+                        format!(
+                            "{}  ↓Inodes Used [{:^10} ({:.1}%)] Free [{:^10}]",
+                            fs.name,
+                            fs.inodes_used,
+                            fs.get_perc_used_inodes(),
+                            fs.inodes_free,
+                        )
+                        .as_str(),
+                    ),
+                )
+                .data(h_inodes.data())
+                .style(fg(*color_enabled, Color::LightCyan))
+                .max(fs.inodes_total)
+                .render(f, area[0]);
+        } else {
+            let h_used = match app
+                .histogram_map
+                .get_zoomed(&HistogramKind::FileSystemUsedSpace(fs.name.clone()), &view)
+            {
+                Some(h) => h,
+                None => return,
+            };
+            let full_eta = match app.histogram_map.get_zoomed(
+                &HistogramKind::FileSystemUsedSpace(fs.name.clone()),
+                &View::Default,
+            ) {
+                Some(h_used_full) => {
+                    estimate_full_eta(h_used_full.data(), fs.size_bytes, fs.get_used_bytes())
+                }
+                None => "unknown".to_string(),
+            };
+            Sparkline::default()
+                .block(
+                    Block::default().title(
+                        format!(
+                            "{}  ↓Used [{:^10} ({:.1}%)] Free [{:^10} ({:.1}%)] Size [{:^10}] Full in {:}",
+                            fs.name,
+                            used,
+                            fs.get_perc_used_space(),
+                            free,
+                            fs.get_perc_free_space(),
+                            size,
+                            full_eta
+                        )
+                        .as_str(),
+                    ),
+                )
+                .data(h_used.data())
+                .style(fg(*color_enabled, Color::LightYellow))
+                .max(fs.size_bytes)
+                .render(f, area[0]);
+        }
         let columns = Layout::default()
             .margin(1)
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
             .split(area[1]);
-        let rhs_style = Style::default().fg(Color::Green);
+        let rhs_style = fg(*color_enabled, Color::Green);
         let text = vec![
             Spans::from(vec![
                 Span::raw("Name:                  ".to_string()),
@@ -211,6 +441,10 @@ fn disk_usage(
                 Span::raw("Mount Point:           ".to_string()),
                 Span::styled(fs.mount_point.to_string_lossy(), rhs_style),
             ]),
+            Spans::from(vec![
+                Span::raw("Kind:                  ".to_string()),
+                Span::styled(format!("{:?}", fs.disk_kind), rhs_style),
+            ]),
         ];
         Paragraph::new(text).render(f, columns[0]);
         let text = vec![
@@ -226,6 +460,18 @@ fn disk_usage(
                 Span::raw("Free:                  ".to_string()),
                 Span::styled(free, rhs_style),
             ]),
+            Spans::from(vec![
+                Span::raw("Inodes Used:           ".to_string()),
+                Span::styled(
+                    format!(
+                        "{} / {} ({:.1}%)",
+                        fs.inodes_used,
+                        fs.inodes_total,
+                        fs.get_perc_used_inodes()
+                    ),
+                    rhs_style,
+                ),
+            ]),
         ];
         Paragraph::new(text).render(f, columns[1]);
     }
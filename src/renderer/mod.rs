@@ -0,0 +1,61 @@
+/**
+ * Copyright 2019-2022, Benjamin Vaisvil and the zenith contributors
+ */
+pub mod disk;
+
+use crate::histogram::View;
+use std::io::Stdout;
+use tui::backend::CrosstermBackend;
+use tui::layout::{Constraint, Direction, Layout, Rect};
+use tui::style::Style;
+use tui::widgets::{Block, Borders, Widget};
+use tui::Frame;
+
+pub type ZBackend = CrosstermBackend<Stdout>;
+
+/// Which of the two sub-views a pane with both an activity and a usage mode is showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileSystemDisplay {
+    Activity,
+    Usage,
+}
+
+impl Default for FileSystemDisplay {
+    fn default() -> Self {
+        FileSystemDisplay::Usage
+    }
+}
+
+/// Adapter so widgets can be rendered with `widget.render(f, area)` instead of the
+/// more verbose `f.render_widget(widget, area)`.
+pub trait Render {
+    fn render(self, f: &mut Frame<'_, ZBackend>, area: Rect);
+}
+
+impl<W: Widget> Render for W {
+    fn render(self, f: &mut Frame<'_, ZBackend>, area: Rect) {
+        f.render_widget(self, area);
+    }
+}
+
+/// Draws the bordered, titled outer block shared by every split pane, then splits its
+/// interior into a left list column (30%) and a right content column (70%).
+pub fn split_left_right_pane(
+    title: &str,
+    layout: Rect,
+    f: &mut Frame<'_, ZBackend>,
+    view: View,
+    border_style: Style,
+) -> (Vec<Rect>, View) {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .render(f, layout);
+    let inner = Layout::default()
+        .margin(1)
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
+        .split(layout);
+    (inner, view)
+}